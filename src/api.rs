@@ -1,14 +1,29 @@
-use crate::PicsumClient;
+use crate::cache::CachedImage;
+use crate::{PicsumClient, RetryPolicy};
+use async_stream::try_stream;
+use bytes::Bytes;
+use futures::future::join_all;
+use futures::{Stream, StreamExt};
+use rand::Rng;
 use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use std::cmp::min;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
+use tokio::sync::Semaphore;
 use typed_builder::TypedBuilder;
 
+/// Default number of in-flight requests for
+/// [`PicsumClient::get_images_batch`].
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 8;
+
 #[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone, Serialize, Deserialize)]
 pub enum FileType {
     Jpeg,
     Webp,
+    Png,
 }
 
 impl FileType {
@@ -16,10 +31,53 @@ impl FileType {
         match self {
             FileType::Jpeg => "jpg",
             FileType::Webp => "webp",
+            FileType::Png => "png",
+        }
+    }
+
+    fn as_image_format(&self) -> image::ImageFormat {
+        match self {
+            FileType::Jpeg => image::ImageFormat::Jpeg,
+            FileType::Webp => image::ImageFormat::WebP,
+            FileType::Png => image::ImageFormat::Png,
+        }
+    }
+
+    /// The `Content-Type` value that corresponds to this file type.
+    fn content_type(&self) -> &'static str {
+        match self {
+            FileType::Jpeg => "image/jpeg",
+            FileType::Webp => "image/webp",
+            FileType::Png => "image/png",
         }
     }
 }
 
+/// Target format for [`Image::transcode`].
+///
+/// `Jpeg` and `Webp` are passthrough re-encodes of what Picsum already
+/// serves; `Avif`, `Jxl`, and `Png` let a caller locally auto-optimize a
+/// downloaded image into a format Picsum doesn't serve.
+///
+/// `Avif` and `Jxl` are both opt-in, feature-gated variants, so that callers
+/// who only need `Jpeg`/`Webp`/`Png` aren't forced onto a native toolchain
+/// they don't need to build this crate at all:
+/// - `Avif` requires the `avif` feature, which enables `image`'s
+///   `avif-encoder` feature and, through it, a `rav1e` build that needs a
+///   NASM toolchain.
+/// - `Jxl` requires the `jxl` feature, which links against the native
+///   `libjxl` C library via `jpegxl-rs`.
+#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash, Copy, Clone, Serialize, Deserialize)]
+pub enum OutputFormat {
+    Jpeg,
+    Webp,
+    Png,
+    #[cfg(feature = "avif")]
+    Avif,
+    #[cfg(feature = "jxl")]
+    Jxl,
+}
+
 #[derive(Error, Debug, Eq, PartialEq, Clone, Hash, Serialize, Deserialize)]
 pub enum RequestError {
     #[error("Request error: {0}")]
@@ -31,6 +89,12 @@ pub enum RequestError {
     #[error("Server error: {0}")]
     ServerError(String),
 
+    /// Picsum answered `429 Too Many Requests` and retries (if any) were
+    /// exhausted. `retry_after` carries the server's `Retry-After` value,
+    /// when present, so callers can wait before trying again themselves.
+    #[error("Rate limited, retry_after: {retry_after:?}")]
+    RateLimited { retry_after: Option<Duration> },
+
     #[error("Unexpected error: {0}")]
     UnexpectedError(String),
 }
@@ -40,6 +104,250 @@ pub enum RequestError {
 pub struct Image {
     pub id: String,
     pub data: Vec<u8>,
+    /// The image's content type, if known: the upstream `Content-Type`
+    /// header for a downloaded image, the type guessed from the extension
+    /// for [`Image::load`], or the target type after [`Image::reencode`] or
+    /// [`Image::transcode`]. Transforms such as [`Image::resize`] keep the
+    /// value of the image they were called on.
+    pub content_type: Option<String>,
+}
+
+impl Image {
+    /// The content type of this image, if known; see the `content_type`
+    /// field.
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// Decode `self.data` into a [`DynamicImage`](image::DynamicImage) for
+    /// local post-processing.
+    pub fn decode(&self) -> Result<image::DynamicImage, RequestError> {
+        image::load_from_memory(&self.data)
+            .map_err(|err| RequestError::UnexpectedError(err.to_string()))
+    }
+
+    /// The pixel dimensions of the decoded image, as `(width, height)`.
+    pub fn dimensions(&self) -> Result<(u32, u32), RequestError> {
+        use image::GenericImageView;
+        Ok(self.decode()?.dimensions())
+    }
+
+    /// Resize the image to exactly `width`x`height`, re-encoding to the same
+    /// [`FileType`] as `content_type` implies, or JPEG if it is unknown.
+    pub fn resize(&self, width: u32, height: u32) -> Result<Image, RequestError> {
+        let resized =
+            self.decode()?
+                .resize_exact(width, height, image::imageops::FilterType::Lanczos3);
+        self.with_decoded(resized)
+    }
+
+    /// Crop the image to a centered square using the shorter side as the
+    /// edge length.
+    pub fn crop_to_square(&self) -> Result<Image, RequestError> {
+        use image::GenericImageView;
+        let decoded = self.decode()?;
+        let (width, height) = decoded.dimensions();
+        let side = width.min(height);
+        let x = (width - side) / 2;
+        let y = (height - side) / 2;
+        let cropped = decoded.crop_imm(x, y, side, side);
+        self.with_decoded(cropped)
+    }
+
+    /// Convert the image to grayscale.
+    pub fn to_grayscale(&self) -> Result<Image, RequestError> {
+        let grayscale = self.decode()?.grayscale();
+        self.with_decoded(grayscale)
+    }
+
+    /// Re-encode the image to `file_type` at the default quality.
+    pub fn reencode(&self, file_type: FileType) -> Result<Image, RequestError> {
+        let decoded = self.decode()?;
+        Ok(Image {
+            id: self.id.clone(),
+            data: encode_image(&decoded, file_type)?,
+            content_type: Some(file_type.content_type().to_string()),
+        })
+    }
+
+    /// Decode this image and re-encode it as `format` at `quality` (`0-100`,
+    /// ignored by lossless formats), without needing a separate
+    /// auto-optimizing server in front of Picsum.
+    pub fn transcode(&self, format: OutputFormat, quality: u8) -> Result<Image, RequestError> {
+        let decoded = self.decode()?;
+        let quality = quality.min(100);
+
+        let data = match format {
+            OutputFormat::Jpeg => encode_jpeg(&decoded, quality)?,
+            OutputFormat::Webp => encode_image(&decoded, FileType::Webp)?,
+            OutputFormat::Png => encode_image(&decoded, FileType::Png)?,
+            #[cfg(feature = "avif")]
+            OutputFormat::Avif => encode_avif(&decoded, quality)?,
+            #[cfg(feature = "jxl")]
+            OutputFormat::Jxl => encode_jxl(&decoded, quality)?,
+        };
+
+        Ok(Image {
+            id: self.id.clone(),
+            data,
+            content_type: Some(output_format_content_type(format).to_string()),
+        })
+    }
+
+    /// Build a new [`Image`] from a transformed [`DynamicImage`], re-encoding
+    /// with the same format as this image (falling back to JPEG when the
+    /// format cannot be determined from `content_type`).
+    fn with_decoded(&self, decoded: image::DynamicImage) -> Result<Image, RequestError> {
+        let file_type = self.inferred_file_type();
+        Ok(Image {
+            id: self.id.clone(),
+            data: encode_image(&decoded, file_type)?,
+            content_type: self.content_type.clone(),
+        })
+    }
+
+    fn inferred_file_type(&self) -> FileType {
+        match self.content_type.as_deref() {
+            Some("image/png") => FileType::Png,
+            Some("image/webp") => FileType::Webp,
+            _ => FileType::Jpeg,
+        }
+    }
+
+    /// Compute a [BlurHash](https://blurha.sh) placeholder string for this
+    /// image, using `x_components` horizontal and `y_components` vertical
+    /// frequency components (each must be in `1..=9`).
+    ///
+    /// Useful for rendering a blurred placeholder while the full image
+    /// loads.
+    pub fn blurhash(&self, x_components: u8, y_components: u8) -> Result<String, RequestError> {
+        crate::blurhash::encode(&self.decode()?, x_components, y_components)
+    }
+
+    /// Write `self.data` to `path`, returning the path actually written to.
+    ///
+    /// If `path` has no extension, one is appended based on
+    /// [`Image::content_type`] (falling back to `.jpg`), so callers can pass
+    /// a bare directory entry like `fixtures/my-image` and get back
+    /// `fixtures/my-image.png`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<PathBuf, RequestError> {
+        let path = self.destination_path(path.as_ref());
+        std::fs::write(&path, &self.data)
+            .map_err(|err| RequestError::UnexpectedError(err.to_string()))?;
+        Ok(path)
+    }
+
+    fn destination_path(&self, path: &Path) -> PathBuf {
+        if path.extension().is_some() {
+            return path.to_path_buf();
+        }
+        path.with_extension(self.inferred_file_type().as_string())
+    }
+
+    /// Read a previously saved image back from `path`.
+    ///
+    /// The returned [`Image::id`] is `path`'s file stem, and
+    /// [`Image::content_type`] is guessed from `path`'s extension. This is
+    /// the companion to [`Image::save`], for round-tripping images through a
+    /// local fixtures directory instead of re-downloading them.
+    pub fn load(path: impl AsRef<Path>) -> Result<Image, RequestError> {
+        let path = path.as_ref();
+        let data =
+            std::fs::read(path).map_err(|err| RequestError::UnexpectedError(err.to_string()))?;
+        let id = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let content_type = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(content_type_for_extension)
+            .map(str::to_string);
+
+        Ok(Image {
+            id,
+            data,
+            content_type,
+        })
+    }
+}
+
+/// Guess a `Content-Type` value from a file extension, mirroring
+/// [`FileType::as_string`] in reverse.
+fn content_type_for_extension(extension: &str) -> Option<&'static str> {
+    match extension.to_ascii_lowercase().as_str() {
+        "jpg" | "jpeg" => Some("image/jpeg"),
+        "png" => Some("image/png"),
+        "webp" => Some("image/webp"),
+        _ => None,
+    }
+}
+
+/// Encode `image` as `file_type`, returning the resulting bytes.
+fn encode_image(image: &image::DynamicImage, file_type: FileType) -> Result<Vec<u8>, RequestError> {
+    let mut data = std::io::Cursor::new(Vec::new());
+    image
+        .write_to(&mut data, file_type.as_image_format())
+        .map_err(|err| RequestError::UnexpectedError(err.to_string()))?;
+    Ok(data.into_inner())
+}
+
+/// The `Content-Type` value that corresponds to [`OutputFormat`].
+fn output_format_content_type(format: OutputFormat) -> &'static str {
+    match format {
+        OutputFormat::Jpeg => "image/jpeg",
+        OutputFormat::Webp => "image/webp",
+        OutputFormat::Png => "image/png",
+        #[cfg(feature = "avif")]
+        OutputFormat::Avif => "image/avif",
+        #[cfg(feature = "jxl")]
+        OutputFormat::Jxl => "image/jxl",
+    }
+}
+
+/// Encode `image` as JPEG at `quality` (`0-100`).
+fn encode_jpeg(image: &image::DynamicImage, quality: u8) -> Result<Vec<u8>, RequestError> {
+    let mut data = Vec::new();
+    image::codecs::jpeg::JpegEncoder::new_with_quality(&mut data, quality)
+        .encode_image(image)
+        .map_err(|err| RequestError::UnexpectedError(err.to_string()))?;
+    Ok(data)
+}
+
+/// Encode `image` as AVIF at `quality` (`0-100`), at the codec's default
+/// encode speed, via `image`'s `avif-encoder` feature.
+#[cfg(feature = "avif")]
+fn encode_avif(image: &image::DynamicImage, quality: u8) -> Result<Vec<u8>, RequestError> {
+    use image::ImageEncoder;
+    let mut data = Vec::new();
+    image::codecs::avif::AvifEncoder::new_with_speed_quality(&mut data, 4, quality)
+        .write_image(
+            image.as_bytes(),
+            image.width(),
+            image.height(),
+            image.color().into(),
+        )
+        .map_err(|err| RequestError::UnexpectedError(err.to_string()))?;
+    Ok(data)
+}
+
+/// Encode `image` as JPEG XL at `quality` (`0-100`), via `jpegxl-rs`'s
+/// bindings to libjxl.
+#[cfg(feature = "jxl")]
+fn encode_jxl(image: &image::DynamicImage, quality: u8) -> Result<Vec<u8>, RequestError> {
+    let rgb = image.to_rgb8();
+    // `jpegxl_rs`'s `quality` is a butteraugli distance, where lower is
+    // better (0 is near-lossless), the inverse of this function's `quality`.
+    let encoder = jpegxl_rs::encoder_builder()
+        .quality((100 - quality) as f32 / 100.0 * 15.0)
+        .build()
+        .map_err(|err| RequestError::UnexpectedError(err.to_string()))?;
+
+    encoder
+        .encode::<u8, u8>(rgb.as_raw(), rgb.width(), rgb.height())
+        .map(|result| result.data)
+        .map_err(|err| RequestError::UnexpectedError(err.to_string()))
 }
 
 #[derive(TypedBuilder)]
@@ -119,7 +427,255 @@ pub struct ImageDetails {
     pub download_url: String,
 }
 
+/// Client-side sort order for [`PicsumClient::list_images`].
+///
+/// Picsum's `/v2/list` endpoint has no `sort` query parameter, so this only
+/// reorders the items within each fetched page rather than across the whole
+/// catalog.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
+pub enum ImageListOrder {
+    /// Preserve the order returned by the server (the default).
+    #[default]
+    None,
+    /// Sort ascending by [`ImageDetails::id`], parsed numerically where
+    /// possible and falling back to a string comparison.
+    Id,
+    /// Sort ascending by [`ImageDetails::author`].
+    Author,
+    /// Sort ascending by `(width, height)`.
+    Dimensions,
+}
+
+impl ImageListOrder {
+    fn sort(&self, images: &mut [ImageDetails]) {
+        match self {
+            ImageListOrder::None => {}
+            ImageListOrder::Id => images.sort_by_key(|image| {
+                image
+                    .id
+                    .parse::<u64>()
+                    .map(Ok)
+                    .unwrap_or_else(|_| Err(image.id.clone()))
+            }),
+            ImageListOrder::Author => images.sort_by(|a, b| a.author.cmp(&b.author)),
+            ImageListOrder::Dimensions => images.sort_by_key(|image| (image.width, image.height)),
+        }
+    }
+}
+
+/// Rate-limit and caching metadata parsed from a response's headers, exposed
+/// alongside the result of a `_with_meta` request so callers can throttle
+/// themselves and perform conditional revalidation without re-parsing raw
+/// headers.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct RateLimitInfo {
+    /// Remaining requests in the current rate-limit window, from
+    /// `X-RateLimit-Remaining`.
+    pub remaining: Option<u32>,
+    /// Total requests allowed per rate-limit window, from
+    /// `X-RateLimit-Limit`.
+    pub limit: Option<u32>,
+    /// Unix timestamp at which the rate-limit window resets, from
+    /// `X-RateLimit-Reset`.
+    pub reset: Option<u64>,
+    /// The `ETag` header, usable as `If-None-Match` on a later request.
+    pub etag: Option<String>,
+    /// The `Last-Modified` header, usable as `If-Modified-Since` on a later
+    /// request.
+    pub last_modified: Option<String>,
+    /// The raw `Cache-Control` header.
+    pub cache_control: Option<String>,
+}
+
+impl RateLimitInfo {
+    fn from_headers(headers: &reqwest::header::HeaderMap) -> Self {
+        let header_num =
+            |name: &str| header_string(headers, name).and_then(|value| value.parse().ok());
+        let header_num_u64 =
+            |name: &str| header_string(headers, name).and_then(|value| value.parse::<u64>().ok());
+
+        Self {
+            remaining: header_num("x-ratelimit-remaining"),
+            limit: header_num("x-ratelimit-limit"),
+            reset: header_num_u64("x-ratelimit-reset"),
+            etag: header_string(headers, "etag"),
+            last_modified: header_string(headers, "last-modified"),
+            cache_control: header_string(headers, "cache-control"),
+        }
+    }
+}
+
+/// Read a header's value as an owned `String`, if present and valid UTF-8.
+fn header_string(headers: &reqwest::header::HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Fraction of the computed backoff delay added back on top, at random, as
+/// jitter to avoid synchronized retries across clients.
+const JITTER_FRACTION: f64 = 0.2;
+
+/// Parse the IMF-fixdate form of an HTTP-date (e.g.
+/// `Sun, 06 Nov 1994 08:49:37 GMT`), the only `Retry-After` date format
+/// RFC 7231 requires senders to generate. The obsolete RFC 850 and asctime
+/// forms that the RFC says recipients *should* also accept are not handled.
+fn parse_http_date(value: &str) -> Option<std::time::SystemTime> {
+    let mut parts = value.split_whitespace();
+    let _weekday = parts.next()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month = match parts.next()? {
+        "Jan" => 1,
+        "Feb" => 2,
+        "Mar" => 3,
+        "Apr" => 4,
+        "May" => 5,
+        "Jun" => 6,
+        "Jul" => 7,
+        "Aug" => 8,
+        "Sep" => 9,
+        "Oct" => 10,
+        "Nov" => 11,
+        "Dec" => 12,
+        _ => return None,
+    };
+    let year: i64 = parts.next()?.parse().ok()?;
+    let mut time = parts.next()?.split(':');
+    let hour: u64 = time.next()?.parse().ok()?;
+    let minute: u64 = time.next()?.parse().ok()?;
+    let second: u64 = time.next()?.parse().ok()?;
+    if parts.next()? != "GMT" {
+        return None;
+    }
+
+    let days = days_from_civil(year, month, day);
+    let seconds = days * 86_400 + (hour * 3_600 + minute * 60 + second) as i64;
+    if seconds < 0 {
+        return None;
+    }
+    Some(std::time::UNIX_EPOCH + Duration::from_secs(seconds as u64))
+}
+
+/// Days since the Unix epoch for a given Gregorian calendar date, per
+/// Howard Hinnant's `days_from_civil` algorithm.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
 impl PicsumClient {
+    /// Send `request`, retrying on a connection error, `429 Too Many
+    /// Requests`, or a `5xx` status, up to the client's configured
+    /// [`RetryPolicy::max_attempts`]. Delays follow `base_delay *
+    /// 2^(attempt - 1)`, capped at `max_delay`, with up to
+    /// [`JITTER_FRACTION`] of that delay added on top as jitter, unless the
+    /// response carries a `Retry-After` header, in which case that value is
+    /// used instead. Any other `4xx` status is returned immediately without
+    /// retrying. If a `429` is still returned once attempts are exhausted,
+    /// it surfaces as [`RequestError::RateLimited`] rather than a generic
+    /// error.
+    async fn send_with_retry(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, RequestError> {
+        let policy = &self.inner.retry_policy;
+        let mut attempt = 0u32;
+
+        loop {
+            let attempt_request = request.try_clone().ok_or_else(|| {
+                RequestError::UnexpectedError("request cannot be retried".to_string())
+            })?;
+
+            match attempt_request.send().await {
+                Ok(res)
+                    if res.status().is_success() || res.status() == StatusCode::NOT_MODIFIED =>
+                {
+                    return Ok(res);
+                }
+                Ok(res) if self.should_retry(res.status(), attempt) => {
+                    let delay = Self::retry_delay(&res, attempt, policy);
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(res) => return Err(Self::status_error(res)),
+                Err(_err) if attempt < policy.max_attempts => {
+                    attempt += 1;
+                    tokio::time::sleep(Self::backoff_delay(attempt, policy)).await;
+                }
+                Err(err) => return Err(RequestError::UnexpectedError(err.to_string())),
+            }
+        }
+    }
+
+    fn should_retry(&self, status: StatusCode, attempt: u32) -> bool {
+        attempt < self.inner.retry_policy.max_attempts
+            && (status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error())
+    }
+
+    fn backoff_delay(attempt: u32, policy: &RetryPolicy) -> Duration {
+        let factor = 1u32
+            .checked_shl(attempt.saturating_sub(1))
+            .unwrap_or(u32::MAX);
+        let delay = policy
+            .base_delay
+            .saturating_mul(factor)
+            .min(policy.max_delay);
+        Self::with_jitter(delay)
+    }
+
+    /// Add up to [`JITTER_FRACTION`] of `delay` back on top, at random.
+    fn with_jitter(delay: Duration) -> Duration {
+        let jitter = delay.mul_f64(rand::thread_rng().gen_range(0.0..JITTER_FRACTION));
+        delay.saturating_add(jitter)
+    }
+
+    /// Parse a `Retry-After` header, which per RFC 7231 is either a number
+    /// of seconds or an HTTP-date naming the point to retry at.
+    fn retry_after(res: &reqwest::Response) -> Option<Duration> {
+        let value = res
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())?;
+
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let target = parse_http_date(value)?;
+        Some(
+            target
+                .duration_since(std::time::SystemTime::now())
+                .unwrap_or_default(),
+        )
+    }
+
+    fn retry_delay(res: &reqwest::Response, attempt: u32, policy: &RetryPolicy) -> Duration {
+        Self::retry_after(res).unwrap_or_else(|| Self::backoff_delay(attempt + 1, policy))
+    }
+
+    fn status_error(res: reqwest::Response) -> RequestError {
+        let retry_after = Self::retry_after(&res);
+
+        match res.error_for_status() {
+            Ok(_) => RequestError::UnexpectedError("unexpected success status".to_string()),
+            Err(err) => match err.status() {
+                Some(StatusCode::TOO_MANY_REQUESTS) => RequestError::RateLimited { retry_after },
+                Some(StatusCode::BAD_REQUEST) => RequestError::InvalidRequest(err.to_string()),
+                Some(StatusCode::INTERNAL_SERVER_ERROR) => {
+                    RequestError::ServerError(err.to_string())
+                }
+                Some(code) => RequestError::UnexpectedError(format!("{} {}", code, err)),
+                None => RequestError::UnexpectedError(err.to_string()),
+            },
+        }
+    }
+
     /// Retrieve image details of a specific image id.
     ///
     /// # Examples
@@ -160,32 +716,16 @@ impl PicsumClient {
     /// # assert_eq!(expected_details, details);
     /// # });
     pub async fn get_image_details(&self, id: &str) -> Result<ImageDetails, RequestError> {
-        let response = self
+        let request = self
             .inner
             .client
-            .get(format!("{}/id/{}/info", self.inner.base_url, id))
-            .send()
-            .await;
-
-        match response {
-            Ok(r) => match r.error_for_status() {
-                Ok(res) => res
-                    .json::<ImageDetails>()
-                    .await
-                    .map_err(|err| RequestError::InvalidResponse(err.to_string())),
-                Err(err) => match err.status() {
-                    Some(StatusCode::BAD_REQUEST) => {
-                        Err(RequestError::InvalidRequest(err.to_string()))
-                    }
-                    Some(StatusCode::INTERNAL_SERVER_ERROR) => {
-                        Err(RequestError::ServerError(err.to_string()))
-                    }
-                    Some(code) => Err(RequestError::UnexpectedError(format!("{} {}", code, err))),
-                    None => Err(RequestError::UnexpectedError(err.to_string())),
-                },
-            },
-            Err(err) => Err(RequestError::UnexpectedError(err.to_string())),
-        }
+            .get(format!("{}/id/{}/info", self.inner.base_url, id));
+
+        self.send_with_retry(request)
+            .await?
+            .json::<ImageDetails>()
+            .await
+            .map_err(|err| RequestError::InvalidResponse(err.to_string()))
     }
 
     /// Retrieve a list of available images.
@@ -224,35 +764,141 @@ impl PicsumClient {
         page: u16,
         limit: u8,
     ) -> Result<Vec<ImageDetails>, RequestError> {
-        let response = self
+        let request = self
             .inner
             .client
             .get(format!("{}/v2/list", self.inner.base_url))
-            .query(&vec![("page", page), ("limit", limit as u16)])
-            .send()
-            .await;
-
-        match response {
-            Ok(r) => match r.error_for_status() {
-                Ok(res) => res
-                    .json::<Vec<ImageDetails>>()
-                    .await
-                    .map_err(|err| RequestError::InvalidResponse(err.to_string())),
-                Err(err) => match err.status() {
-                    Some(StatusCode::BAD_REQUEST) => {
-                        Err(RequestError::InvalidRequest(err.to_string()))
-                    }
-                    Some(StatusCode::INTERNAL_SERVER_ERROR) => {
-                        Err(RequestError::ServerError(err.to_string()))
-                    }
-                    Some(code) => Err(RequestError::UnexpectedError(format!("{} {}", code, err))),
-                    None => Err(RequestError::UnexpectedError(err.to_string())),
-                },
-            },
-            Err(err) => Err(RequestError::UnexpectedError(err.to_string())),
+            .query(&vec![("page", page), ("limit", limit as u16)]);
+
+        self.send_with_retry(request)
+            .await?
+            .json::<Vec<ImageDetails>>()
+            .await
+            .map_err(|err| RequestError::InvalidResponse(err.to_string()))
+    }
+
+    /// Stream the full image catalog, transparently fetching successive
+    /// pages of `page_size` until the server returns a short or empty page.
+    ///
+    /// Each page is reordered locally according to `order` before being
+    /// yielded; see [`ImageListOrder`] for its limitations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::pin_mut;
+    /// use futures::StreamExt;
+    /// use picsum_rs::PicsumClient;
+    /// use picsum_rs::api::ImageListOrder;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let client = PicsumClient::default();
+    /// let stream = client.list_images(10, ImageListOrder::None);
+    /// pin_mut!(stream);
+    ///
+    /// # let mut seen = 0;
+    /// while let Some(result) = stream.next().await {
+    ///     let _details = result.expect("listing the catalog failed");
+    /// #   seen += 1;
+    /// #   if seen >= 10 {
+    /// #       break;
+    /// #   }
+    /// }
+    /// # })
+    /// ```
+    pub fn list_images(
+        &self,
+        page_size: u8,
+        order: ImageListOrder,
+    ) -> impl Stream<Item = Result<ImageDetails, RequestError>> + '_ {
+        try_stream! {
+            let mut page = 1u16;
+            loop {
+                let mut batch = self.get_images(page, page_size).await?;
+                if batch.is_empty() {
+                    break;
+                }
+                let short_page = batch.len() < page_size as usize;
+                order.sort(&mut batch);
+
+                for image in batch {
+                    yield image;
+                }
+
+                if short_page {
+                    break;
+                }
+                page += 1;
+            }
         }
     }
 
+    /// Fetch each id in `ids` concurrently, capping in-flight requests at
+    /// [`DEFAULT_BATCH_CONCURRENCY`]. See
+    /// [`PicsumClient::get_images_batch_with_concurrency`] to configure the
+    /// cap.
+    ///
+    /// Results preserve the order of `ids`, and one id failing never fails
+    /// the rest of the batch; each id carries its own
+    /// `Result<Image, RequestError>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use picsum_rs::PicsumClient;
+    /// use picsum_rs::api::ImageSettings;
+    ///
+    /// # tokio_test::block_on(async {
+    /// // `does-not-exist` fails without affecting the ids around it, and
+    /// // the results come back in the same order as `ids`.
+    /// let ids = ["1", "does-not-exist", "2"];
+    /// let results = PicsumClient::default()
+    ///     .get_images_batch(&ids, &ImageSettings::builder().width(100).height(100).build())
+    ///     .await;
+    ///
+    /// # assert_eq!(ids.len(), results.len());
+    /// for ((id, result), expected_id) in results.iter().zip(ids) {
+    ///     assert_eq!(id, expected_id, "results should preserve the order of `ids`");
+    /// }
+    /// # assert!(results[0].1.is_ok(), "id `1` should succeed");
+    /// # assert!(results[1].1.is_err(), "the invalid id should fail");
+    /// # assert!(results[2].1.is_ok(), "id `2` should still succeed despite `does-not-exist` failing");
+    /// # })
+    /// ```
+    pub async fn get_images_batch(
+        &self,
+        ids: &[&str],
+        settings: &ImageSettings,
+    ) -> Vec<(String, Result<Image, RequestError>)> {
+        self.get_images_batch_with_concurrency(ids, settings, DEFAULT_BATCH_CONCURRENCY)
+            .await
+    }
+
+    /// Like [`PicsumClient::get_images_batch`], but with an explicit cap on
+    /// the number of in-flight requests instead of
+    /// [`DEFAULT_BATCH_CONCURRENCY`].
+    pub async fn get_images_batch_with_concurrency(
+        &self,
+        ids: &[&str],
+        settings: &ImageSettings,
+        concurrency: usize,
+    ) -> Vec<(String, Result<Image, RequestError>)> {
+        let semaphore = Arc::new(Semaphore::new(concurrency.max(1)));
+
+        let requests = ids.iter().map(|&id| {
+            let semaphore = Arc::clone(&semaphore);
+            async move {
+                let _permit = semaphore
+                    .acquire()
+                    .await
+                    .expect("semaphore is never closed");
+                (id.to_string(), self.get_image_by_id(id, settings).await)
+            }
+        });
+
+        join_all(requests).await
+    }
+
     /// Retrieve a specific image by its id.
     ///
     /// # Examples
@@ -299,62 +945,87 @@ impl PicsumClient {
         id: &str,
         image_settings: &ImageSettings,
     ) -> Result<Image, RequestError> {
-        let response = self
-            .inner
-            .client
-            .get(format!(
-                "{}/id/{}/{}/{}.{}",
-                self.inner.base_url,
-                id,
-                image_settings.width,
-                image_settings.height,
-                image_settings.file_type.as_string()
-            ))
-            .query(&image_settings.generate_query_params())
-            .send()
-            .await;
-
-        match response {
-            Ok(r) => match r.error_for_status() {
-                Ok(res) => {
-                    let id = match res.headers().get("picsum-id") {
-                        None => {
-                            return Err(RequestError::UnexpectedError(
-                                "Couldn't retrieve `picsum-id` header.".into(),
-                            ));
-                        }
-                        Some(v) => match v.to_str() {
-                            Ok(value) => value,
-                            Err(e) => return Err(RequestError::UnexpectedError(e.to_string())),
-                        },
-                    };
-
-                    Ok(Image {
-                        id: id.into(),
-                        data: match res.bytes().await {
-                            Ok(bytes) => bytes.to_vec(),
-                            Err(err) => {
-                                return Err(RequestError::UnexpectedError(format!(
-                                    "Couldn't read response body: {}",
-                                    err
-                                )));
-                            }
-                        },
-                    })
-                }
-                Err(err) => match err.status() {
-                    Some(StatusCode::BAD_REQUEST) => {
-                        Err(RequestError::InvalidRequest(err.to_string()))
-                    }
-                    Some(StatusCode::INTERNAL_SERVER_ERROR) => {
-                        Err(RequestError::ServerError(err.to_string()))
-                    }
-                    Some(code) => Err(RequestError::UnexpectedError(format!("{} {}", code, err))),
-                    None => Err(RequestError::UnexpectedError(err.to_string())),
-                },
-            },
-            Err(err) => Err(RequestError::UnexpectedError(err.to_string())),
-        }
+        let url = format!(
+            "{}/id/{}/{}/{}.{}",
+            self.inner.base_url,
+            id,
+            image_settings.width,
+            image_settings.height,
+            image_settings.file_type.as_string()
+        );
+
+        self.get_image_cached(url, image_settings.generate_query_params())
+            .await
+    }
+
+    /// Retrieve a specific image by its id.
+    ///
+    /// This is an alias for [`PicsumClient::get_image`], named to mirror
+    /// [`PicsumClient::get_seeded_image`] for callers who want to address a
+    /// specific photo rather than a deterministic seed or a random one.
+    pub async fn get_image_by_id(
+        &self,
+        id: &str,
+        image_settings: &ImageSettings,
+    ) -> Result<Image, RequestError> {
+        self.get_image(id, image_settings).await
+    }
+
+    /// Retrieve a deterministic placeholder image for `seed`.
+    ///
+    /// Unlike [`PicsumClient::get_random_image`], requesting the same `seed`
+    /// with the same [`ImageSettings`] always returns the same underlying
+    /// photo, which makes this useful for tests and fixtures.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use picsum_rs::PicsumClient;
+    /// use picsum_rs::api::ImageSettings;
+    ///
+    /// # tokio_test::block_on(async {
+    /// # let result =
+    /// // Retrieve the deterministic image for the seed `my-fixture` in the size 400x400px.
+    /// match PicsumClient::default()
+    ///     .get_seeded_image("my-fixture", &ImageSettings::builder().width(400).height(400).build())
+    ///     .await
+    /// {
+    ///     Ok(image) => {
+    /// #       Ok(
+    ///         image
+    /// #       )
+    ///     }
+    ///     Err(e) => {
+    ///         // Do your error handling
+    ///         # Err(e)
+    ///     }
+    /// }
+    /// # ;
+    /// # assert!(
+    /// #    result.is_ok(),
+    /// #    "Retrieving the seeded image for `my-fixture` failed: {}",
+    /// #    result.unwrap_err().to_string()
+    /// # );
+    /// # let image = result.unwrap();
+    /// # assert!(image.data.len() > 0);
+    /// # })
+    /// ```
+    pub async fn get_seeded_image(
+        &self,
+        seed: &str,
+        image_settings: &ImageSettings,
+    ) -> Result<Image, RequestError> {
+        let url = format!(
+            "{}/seed/{}/{}/{}.{}",
+            self.inner.base_url,
+            seed,
+            image_settings.width,
+            image_settings.height,
+            image_settings.file_type.as_string()
+        );
+
+        self.get_image_cached(url, image_settings.generate_query_params())
+            .await
     }
 
     /// Retrieve a random image with the given settings
@@ -397,7 +1068,289 @@ impl PicsumClient {
         &self,
         image_settings: &ImageSettings,
     ) -> Result<Image, RequestError> {
-        let response = self
+        let url = format!(
+            "{}/{}/{}.{}",
+            self.inner.base_url,
+            image_settings.width,
+            image_settings.height,
+            image_settings.file_type.as_string()
+        );
+
+        self.get_image_cached(url, image_settings.generate_query_params())
+            .await
+    }
+
+    /// Retrieve a specific image by its id, yielding its body in chunks
+    /// instead of buffering the full payload in memory.
+    ///
+    /// Returns the `picsum-id` up front, then a [`Stream`] of the body, so a
+    /// caller piping a large original straight to disk or an HTTP response
+    /// doesn't need a full in-memory copy first. Bypasses the
+    /// conditional-request cache, since there is no buffered body to cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::{pin_mut, StreamExt};
+    /// use picsum_rs::PicsumClient;
+    /// use picsum_rs::api::ImageSettings;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let (id, stream) = PicsumClient::default()
+    ///     .get_image_stream("1", &ImageSettings::builder().width(100).height(100).build())
+    ///     .await
+    ///     .expect("streaming the image with id 1 failed");
+    /// # assert_eq!("1", id);
+    /// pin_mut!(stream);
+    ///
+    /// let mut body = Vec::new();
+    /// while let Some(chunk) = stream.next().await {
+    ///     body.extend_from_slice(&chunk.expect("reading a chunk failed"));
+    /// }
+    /// # assert!(!body.is_empty());
+    /// # })
+    /// ```
+    pub async fn get_image_stream(
+        &self,
+        id: &str,
+        image_settings: &ImageSettings,
+    ) -> Result<(String, impl Stream<Item = Result<Bytes, RequestError>>), RequestError> {
+        let url = format!(
+            "{}/id/{}/{}/{}.{}",
+            self.inner.base_url,
+            id,
+            image_settings.width,
+            image_settings.height,
+            image_settings.file_type.as_string()
+        );
+
+        self.get_image_stream_from(url, image_settings.generate_query_params())
+            .await
+    }
+
+    /// Retrieve a deterministic placeholder image for `seed`, yielding its
+    /// body in chunks instead of buffering the full payload in memory. See
+    /// [`PicsumClient::get_image_stream`] for the streaming behavior and
+    /// [`PicsumClient::get_seeded_image`] for the non-streaming equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::{pin_mut, StreamExt};
+    /// use picsum_rs::PicsumClient;
+    /// use picsum_rs::api::ImageSettings;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let (_id, stream) = PicsumClient::default()
+    ///     .get_seeded_image_stream(
+    ///         "my-fixture",
+    ///         &ImageSettings::builder().width(100).height(100).build(),
+    ///     )
+    ///     .await
+    ///     .expect("streaming the seeded image failed");
+    /// pin_mut!(stream);
+    ///
+    /// let mut body = Vec::new();
+    /// while let Some(chunk) = stream.next().await {
+    ///     body.extend_from_slice(&chunk.expect("reading a chunk failed"));
+    /// }
+    /// # assert!(!body.is_empty());
+    /// # })
+    /// ```
+    pub async fn get_seeded_image_stream(
+        &self,
+        seed: &str,
+        image_settings: &ImageSettings,
+    ) -> Result<(String, impl Stream<Item = Result<Bytes, RequestError>>), RequestError> {
+        let url = format!(
+            "{}/seed/{}/{}/{}.{}",
+            self.inner.base_url,
+            seed,
+            image_settings.width,
+            image_settings.height,
+            image_settings.file_type.as_string()
+        );
+
+        self.get_image_stream_from(url, image_settings.generate_query_params())
+            .await
+    }
+
+    /// Retrieve a random image with the given settings, yielding its body in
+    /// chunks instead of buffering the full payload in memory. See
+    /// [`PicsumClient::get_image_stream`] for the streaming behavior and
+    /// [`PicsumClient::get_random_image`] for the non-streaming equivalent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::{pin_mut, StreamExt};
+    /// use picsum_rs::PicsumClient;
+    /// use picsum_rs::api::ImageSettings;
+    ///
+    /// # tokio_test::block_on(async {
+    /// let (_id, stream) = PicsumClient::default()
+    ///     .get_random_image_stream(&ImageSettings::builder().width(100).height(100).build())
+    ///     .await
+    ///     .expect("streaming a random image failed");
+    /// pin_mut!(stream);
+    ///
+    /// let mut body = Vec::new();
+    /// while let Some(chunk) = stream.next().await {
+    ///     body.extend_from_slice(&chunk.expect("reading a chunk failed"));
+    /// }
+    /// # assert!(!body.is_empty());
+    /// # })
+    /// ```
+    pub async fn get_random_image_stream(
+        &self,
+        image_settings: &ImageSettings,
+    ) -> Result<(String, impl Stream<Item = Result<Bytes, RequestError>>), RequestError> {
+        let url = format!(
+            "{}/{}/{}.{}",
+            self.inner.base_url,
+            image_settings.width,
+            image_settings.height,
+            image_settings.file_type.as_string()
+        );
+
+        self.get_image_stream_from(url, image_settings.generate_query_params())
+            .await
+    }
+
+    /// Request `url` with `query_params` and return the `picsum-id` header
+    /// alongside a [`Stream`] of the response body, read in chunks as they
+    /// arrive rather than buffered up front. Used by
+    /// [`PicsumClient::get_image_stream`], [`PicsumClient::get_seeded_image_stream`],
+    /// and [`PicsumClient::get_random_image_stream`], which only differ in
+    /// the URL they request.
+    async fn get_image_stream_from(
+        &self,
+        url: String,
+        query_params: Vec<(&str, String)>,
+    ) -> Result<(String, impl Stream<Item = Result<Bytes, RequestError>>), RequestError> {
+        let request = self.inner.client.get(&url).query(&query_params);
+        let res = self.send_with_retry(request).await?;
+
+        let id = match res.headers().get("picsum-id") {
+            None => {
+                return Err(RequestError::UnexpectedError(
+                    "Couldn't retrieve `picsum-id` header.".into(),
+                ));
+            }
+            Some(v) => match v.to_str() {
+                Ok(value) => value.to_string(),
+                Err(e) => return Err(RequestError::UnexpectedError(e.to_string())),
+            },
+        };
+
+        let stream = res
+            .bytes_stream()
+            .map(|chunk| chunk.map_err(|err| RequestError::UnexpectedError(err.to_string())));
+
+        Ok((id, stream))
+    }
+
+    /// Request `url` with `query_params`, transparently revalidating and
+    /// reading through the client's [`ImageCache`](crate::cache::ImageCache)
+    /// when one is configured.
+    ///
+    /// On a cache hit, the request carries `If-None-Match`/
+    /// `If-Modified-Since`; a `304 Not Modified` response returns the
+    /// previously cached image instead of re-reading the body. Used by
+    /// [`PicsumClient::get_image`], [`PicsumClient::get_seeded_image`], and
+    /// [`PicsumClient::get_random_image`], which only differ in the URL they
+    /// request.
+    async fn get_image_cached(
+        &self,
+        url: String,
+        query_params: Vec<(&str, String)>,
+    ) -> Result<Image, RequestError> {
+        let cache_key = format!("{}?{:?}", url, query_params);
+
+        let cached = self
+            .inner
+            .cache
+            .as_ref()
+            .and_then(|cache| cache.get(&cache_key));
+
+        let mut request = self.inner.client.get(&url).query(&query_params);
+        if let Some(entry) = &cached {
+            if let Some(etag) = &entry.etag {
+                request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &entry.last_modified {
+                request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+
+        let res = self.send_with_retry(request).await?;
+
+        if res.status() == StatusCode::NOT_MODIFIED {
+            return cached.map(|entry| entry.image).ok_or_else(|| {
+                RequestError::UnexpectedError(
+                    "received 304 Not Modified without a cached entry".to_string(),
+                )
+            });
+        }
+
+        let etag = header_string(res.headers(), "etag");
+        let last_modified = header_string(res.headers(), "last-modified");
+        let image = Self::image_from_response(res).await?;
+
+        if let Some(cache) = &self.inner.cache {
+            cache.put(
+                cache_key,
+                CachedImage {
+                    etag,
+                    last_modified,
+                    image: image.clone(),
+                },
+            );
+        }
+
+        Ok(image)
+    }
+
+    /// Retrieve a random image with the given settings, alongside
+    /// [`RateLimitInfo`] parsed from the response headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use picsum_rs::PicsumClient;
+    /// use picsum_rs::api::ImageSettings;
+    ///
+    /// # tokio_test::block_on(async {
+    /// # let result =
+    /// match PicsumClient::default()
+    ///     .get_random_image_with_meta(&ImageSettings::builder().width(400).height(400).build())
+    ///     .await
+    /// {
+    ///     Ok((image, meta)) => {
+    /// #       Ok(
+    ///         (image, meta)
+    /// #       )
+    ///     }
+    ///     Err(e) => {
+    ///         // Do your error handling
+    ///         # Err(e)
+    ///     }
+    /// }
+    /// # ;
+    /// # assert!(
+    /// #    result.is_ok(),
+    /// #    "Random image request failed: {}",
+    /// #    result.unwrap_err().to_string()
+    /// # );
+    /// # let (image, _meta) = result.unwrap();
+    /// # assert!(image.data.len() > 0);
+    /// # })
+    /// ```
+    pub async fn get_random_image_with_meta(
+        &self,
+        image_settings: &ImageSettings,
+    ) -> Result<(Image, RateLimitInfo), RequestError> {
+        let request = self
             .inner
             .client
             .get(format!(
@@ -407,50 +1360,219 @@ impl PicsumClient {
                 image_settings.height,
                 image_settings.file_type.as_string()
             ))
-            .query(&image_settings.generate_query_params())
-            .send()
-            .await;
-
-        match response {
-            Ok(r) => match r.error_for_status() {
-                Ok(res) => {
-                    let id = match res.headers().get("picsum-id") {
-                        None => {
-                            return Err(RequestError::UnexpectedError(
-                                "Couldn't retrieve `picsum-id` header.".into(),
-                            ));
-                        }
-                        Some(v) => match v.to_str() {
-                            Ok(value) => value,
-                            Err(e) => return Err(RequestError::UnexpectedError(e.to_string())),
-                        },
-                    };
-
-                    Ok(Image {
-                        id: id.into(),
-                        data: match res.bytes().await {
-                            Ok(bytes) => bytes.to_vec(),
-                            Err(err) => {
-                                return Err(RequestError::UnexpectedError(format!(
-                                    "Couldn't read response body: {}",
-                                    err
-                                )));
-                            }
-                        },
-                    })
-                }
-                Err(err) => match err.status() {
-                    Some(StatusCode::BAD_REQUEST) => {
-                        Err(RequestError::InvalidRequest(err.to_string()))
-                    }
-                    Some(StatusCode::INTERNAL_SERVER_ERROR) => {
-                        Err(RequestError::ServerError(err.to_string()))
-                    }
-                    Some(code) => Err(RequestError::UnexpectedError(format!("{} {}", code, err))),
-                    None => Err(RequestError::UnexpectedError(err.to_string())),
-                },
+            .query(&image_settings.generate_query_params());
+
+        let res = self.send_with_retry(request).await?;
+        let meta = RateLimitInfo::from_headers(res.headers());
+        let image = Self::image_from_response(res).await?;
+        Ok((image, meta))
+    }
+
+    /// Build an [`Image`] from a successful response, reading the
+    /// `picsum-id` header and the response body.
+    async fn image_from_response(res: reqwest::Response) -> Result<Image, RequestError> {
+        let id = match res.headers().get("picsum-id") {
+            None => {
+                return Err(RequestError::UnexpectedError(
+                    "Couldn't retrieve `picsum-id` header.".into(),
+                ));
+            }
+            Some(v) => match v.to_str() {
+                Ok(value) => value.to_string(),
+                Err(e) => return Err(RequestError::UnexpectedError(e.to_string())),
             },
-            Err(err) => Err(RequestError::UnexpectedError(err.to_string())),
+        };
+        let content_type = header_string(res.headers(), "content-type");
+
+        let data = match res.bytes().await {
+            Ok(bytes) => bytes.to_vec(),
+            Err(err) => {
+                return Err(RequestError::UnexpectedError(format!(
+                    "Couldn't read response body: {}",
+                    err
+                )));
+            }
+        };
+
+        Ok(Image {
+            id,
+            data,
+            content_type,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(max_attempts: u32, base_delay: Duration, max_delay: Duration) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay,
+            max_delay,
         }
     }
+
+    #[test]
+    fn backoff_delay_doubles_each_attempt_before_jitter() {
+        let policy = policy(10, Duration::from_millis(100), Duration::from_secs(60));
+
+        // Jitter only ever adds on top, so the un-jittered delay is a lower
+        // bound on what `backoff_delay` returns.
+        assert!(PicsumClient::backoff_delay(1, &policy) >= Duration::from_millis(100));
+        assert!(PicsumClient::backoff_delay(2, &policy) >= Duration::from_millis(200));
+        assert!(PicsumClient::backoff_delay(3, &policy) >= Duration::from_millis(400));
+    }
+
+    #[test]
+    fn backoff_delay_is_capped_at_max_delay() {
+        let policy = policy(64, Duration::from_millis(100), Duration::from_secs(1));
+
+        // At a high enough attempt count the exponential term would dwarf
+        // `max_delay`; jitter can only add up to `JITTER_FRACTION` on top.
+        let delay = PicsumClient::backoff_delay(20, &policy);
+        assert!(delay <= Duration::from_secs(1).mul_f64(1.0 + JITTER_FRACTION));
+    }
+
+    #[test]
+    fn with_jitter_never_shrinks_the_delay() {
+        let delay = Duration::from_millis(500);
+        let jittered = PicsumClient::with_jitter(delay);
+
+        assert!(jittered >= delay);
+        assert!(jittered <= delay.mul_f64(1.0 + JITTER_FRACTION));
+    }
+
+    #[test]
+    fn should_retry_respects_max_attempts_and_status() {
+        let client = PicsumClient::builder().max_retries(2).build();
+
+        assert!(client.should_retry(StatusCode::TOO_MANY_REQUESTS, 0));
+        assert!(client.should_retry(StatusCode::INTERNAL_SERVER_ERROR, 1));
+        assert!(!client.should_retry(StatusCode::TOO_MANY_REQUESTS, 2));
+        assert!(!client.should_retry(StatusCode::BAD_REQUEST, 0));
+        assert!(!client.should_retry(StatusCode::OK, 0));
+    }
+
+    #[test]
+    fn should_retry_is_false_when_retries_disabled() {
+        let client = PicsumClient::default();
+        assert!(!client.should_retry(StatusCode::TOO_MANY_REQUESTS, 0));
+    }
+
+    #[test]
+    fn parse_http_date_matches_known_unix_timestamp() {
+        let target =
+            parse_http_date("Sun, 06 Nov 1994 08:49:37 GMT").expect("should parse IMF-fixdate");
+        assert_eq!(
+            target
+                .duration_since(std::time::UNIX_EPOCH)
+                .expect("should be after the epoch")
+                .as_secs(),
+            784_111_777
+        );
+    }
+
+    #[test]
+    fn parse_http_date_rejects_malformed_input() {
+        assert!(parse_http_date("60").is_none());
+        assert!(parse_http_date("not a date").is_none());
+    }
+
+    /// Build an in-memory [`Image`] fixture, encoded as `file_type`, without
+    /// touching the network.
+    fn fixture_image(width: u32, height: u32, rgb: [u8; 3], file_type: FileType) -> Image {
+        let decoded = image::DynamicImage::ImageRgb8(image::RgbImage::from_fn(
+            width,
+            height,
+            |_, _| image::Rgb(rgb),
+        ));
+        Image {
+            id: "fixture".to_string(),
+            data: encode_image(&decoded, file_type).expect("fixture should encode"),
+            content_type: Some(file_type.content_type().to_string()),
+        }
+    }
+
+    #[test]
+    fn resize_changes_dimensions_and_stays_decodable() {
+        let image = fixture_image(4, 4, [200, 100, 50], FileType::Png);
+        let resized = image.resize(2, 3).expect("resize should succeed");
+        assert_eq!(
+            resized.dimensions().expect("resized image should decode"),
+            (2, 3)
+        );
+    }
+
+    #[test]
+    fn crop_to_square_uses_the_shorter_side() {
+        let image = fixture_image(6, 4, [10, 20, 30], FileType::Png);
+        let cropped = image.crop_to_square().expect("crop should succeed");
+        assert_eq!(
+            cropped.dimensions().expect("cropped image should decode"),
+            (4, 4)
+        );
+    }
+
+    #[test]
+    fn to_grayscale_equalizes_color_channels() {
+        let image = fixture_image(4, 4, [200, 50, 10], FileType::Png);
+        let grayscale = image.to_grayscale().expect("grayscale should succeed");
+        let decoded = grayscale
+            .decode()
+            .expect("grayscale image should decode")
+            .to_rgb8();
+        let pixel = decoded.get_pixel(0, 0);
+        assert_eq!(pixel[0], pixel[1]);
+        assert_eq!(pixel[1], pixel[2]);
+    }
+
+    #[test]
+    fn reencode_switches_file_type_and_content_type() {
+        let image = fixture_image(4, 4, [1, 2, 3], FileType::Png);
+        let reencoded = image
+            .reencode(FileType::Webp)
+            .expect("reencode should succeed");
+
+        assert_eq!(reencoded.content_type.as_deref(), Some("image/webp"));
+        assert!(reencoded.decode().is_ok());
+    }
+
+    #[test]
+    fn transcode_produces_decodable_output_for_each_unfeatured_format() {
+        let image = fixture_image(4, 4, [5, 6, 7], FileType::Png);
+
+        for format in [OutputFormat::Jpeg, OutputFormat::Webp, OutputFormat::Png] {
+            let transcoded = image
+                .transcode(format, 80)
+                .unwrap_or_else(|err| panic!("transcode to {format:?} should succeed: {err}"));
+
+            assert!(transcoded.decode().is_ok());
+            assert_eq!(
+                transcoded.content_type.as_deref(),
+                Some(output_format_content_type(format))
+            );
+        }
+    }
+
+    #[test]
+    fn save_appends_the_inferred_extension_and_load_round_trips() {
+        let image = fixture_image(3, 3, [9, 9, 9], FileType::Png);
+        let dir =
+            std::env::temp_dir().join(format!("picsum-rs-save-load-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).expect("tempdir should be creatable");
+
+        let path = image
+            .save(dir.join("no-extension"))
+            .expect("save should succeed");
+        assert_eq!(path.extension().and_then(|ext| ext.to_str()), Some("png"));
+
+        let loaded = Image::load(&path).expect("load should succeed");
+        assert_eq!(loaded.id, "no-extension");
+        assert_eq!(loaded.data, image.data);
+        assert_eq!(loaded.content_type.as_deref(), Some("image/png"));
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
 }
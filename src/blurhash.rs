@@ -0,0 +1,217 @@
+//! A from-scratch implementation of the [BlurHash](https://blurha.sh)
+//! algorithm, used by [`crate::api::Image::blurhash`] to produce a compact
+//! placeholder string for a downloaded image.
+
+use crate::api::RequestError;
+use image::{DynamicImage, GenericImageView};
+use std::f64::consts::PI;
+
+const BASE83_ALPHABET: &[u8] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz#$%*+,-.:;=?@[]^_{|}~";
+
+/// Encode `image` as a BlurHash string using `x_components` horizontal and
+/// `y_components` vertical frequency components.
+///
+/// Both must be within `1..=9`, matching the range the BlurHash format can
+/// represent in its single size-flag character.
+pub(crate) fn encode(
+    image: &DynamicImage,
+    x_components: u8,
+    y_components: u8,
+) -> Result<String, RequestError> {
+    if !(1..=9).contains(&x_components) || !(1..=9).contains(&y_components) {
+        return Err(RequestError::InvalidRequest(format!(
+            "x_components and y_components must be between 1 and 9, got ({}, {})",
+            x_components, y_components
+        )));
+    }
+
+    let rgb = image.to_rgb8();
+    let (width, height) = image.dimensions();
+    let (width, height) = (width as f64, height as f64);
+
+    let mut factors = Vec::with_capacity(x_components as usize * y_components as usize);
+    for j in 0..y_components {
+        for i in 0..x_components {
+            factors.push(average_basis(&rgb, width, height, i, j));
+        }
+    }
+
+    let mut result = String::new();
+    result.push_str(&encode83(
+        (x_components as i32 - 1) + (y_components as i32 - 1) * 9,
+        1,
+    ));
+
+    let dc = factors[0];
+    let ac = &factors[1..];
+
+    let maximum_value = if let Some(actual_max) = ac
+        .iter()
+        .flat_map(|&(r, g, b)| [r.abs(), g.abs(), b.abs()])
+        .fold(None, |max, value| match max {
+            Some(max) if max >= value => Some(max),
+            _ => Some(value),
+        }) {
+        let quantised_maximum_value = ((actual_max * 166.0 - 0.5).floor().clamp(0.0, 82.0)) as i32;
+        result.push_str(&encode83(quantised_maximum_value, 1));
+        (quantised_maximum_value + 1) as f64 / 166.0
+    } else {
+        result.push_str(&encode83(0, 1));
+        1.0
+    };
+
+    result.push_str(&encode83(encode_dc(dc), 4));
+
+    for &component in ac {
+        result.push_str(&encode83(encode_ac(component, maximum_value), 2));
+    }
+
+    Ok(result)
+}
+
+/// Compute the `(i, j)` basis coefficient averaged over every pixel, as
+/// `(r, g, b)` in linear light.
+fn average_basis(rgb: &image::RgbImage, width: f64, height: f64, i: u8, j: u8) -> (f64, f64, f64) {
+    let normalisation = if i == 0 && j == 0 { 1.0 } else { 2.0 };
+    let (mut r, mut g, mut b) = (0.0, 0.0, 0.0);
+
+    for (x, y, pixel) in rgb.enumerate_pixels() {
+        let basis =
+            (PI * i as f64 * x as f64 / width).cos() * (PI * j as f64 * y as f64 / height).cos();
+        r += basis * srgb_to_linear(pixel.0[0]);
+        g += basis * srgb_to_linear(pixel.0[1]);
+        b += basis * srgb_to_linear(pixel.0[2]);
+    }
+
+    let scale = normalisation / (width * height);
+    (r * scale, g * scale, b * scale)
+}
+
+fn srgb_to_linear(value: u8) -> f64 {
+    let c = value as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f64) -> i32 {
+    let v = value.clamp(0.0, 1.0);
+    let c = if v <= 0.0031308 {
+        v * 12.92
+    } else {
+        1.055 * v.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as i32
+}
+
+fn encode_dc((r, g, b): (f64, f64, f64)) -> i32 {
+    (linear_to_srgb(r) << 16) | (linear_to_srgb(g) << 8) | linear_to_srgb(b)
+}
+
+fn encode_ac((r, g, b): (f64, f64, f64), maximum_value: f64) -> i32 {
+    let quant = |value: f64| -> i32 {
+        (sign_pow(value / maximum_value, 0.5) * 9.0 + 9.5)
+            .floor()
+            .clamp(0.0, 18.0) as i32
+    };
+    quant(r) * 19 * 19 + quant(g) * 19 + quant(b)
+}
+
+fn sign_pow(value: f64, exponent: f64) -> f64 {
+    value.abs().powf(exponent).copysign(value)
+}
+
+/// Encode `value` as `length` base83 digits, most significant first.
+fn encode83(value: i32, length: usize) -> String {
+    let mut digits = vec![0u8; length];
+    let mut value = value;
+    for digit in digits.iter_mut().rev() {
+        *digit = BASE83_ALPHABET[(value % 83) as usize];
+        value /= 83;
+    }
+    String::from_utf8(digits).expect("base83 alphabet is ASCII")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{DynamicImage, RgbImage};
+
+    fn solid_color(width: u32, height: u32, rgb: [u8; 3]) -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |_, _| image::Rgb(rgb)))
+    }
+
+    #[test]
+    fn rejects_out_of_range_components() {
+        let image = solid_color(4, 4, [128, 128, 128]);
+
+        assert!(encode(&image, 0, 3).is_err());
+        assert!(encode(&image, 3, 10).is_err());
+        assert!(encode(&image, 1, 1).is_ok());
+        assert!(encode(&image, 9, 9).is_ok());
+    }
+
+    #[test]
+    fn length_matches_component_count() {
+        let image = solid_color(8, 8, [200, 100, 50]);
+
+        // 1 size char + 1 max-value char + 4 DC chars + 2 chars per AC
+        // component, per the BlurHash spec.
+        let hash = encode(&image, 3, 2).expect("encode should succeed");
+        assert_eq!(hash.len(), 1 + 1 + 4 + 2 * (3 * 2 - 1));
+    }
+
+    #[test]
+    fn only_uses_base83_alphabet() {
+        let image = solid_color(6, 6, [10, 200, 90]);
+        let hash = encode(&image, 4, 3).expect("encode should succeed");
+
+        assert!(hash.bytes().all(|b| BASE83_ALPHABET.contains(&b)));
+    }
+
+    #[test]
+    fn is_deterministic_for_the_same_image() {
+        let image = solid_color(5, 5, [1, 2, 3]);
+
+        assert_eq!(
+            encode(&image, 4, 4).expect("encode should succeed"),
+            encode(&image, 4, 4).expect("encode should succeed"),
+        );
+    }
+
+    #[test]
+    fn encode83_round_trips_through_known_digits() {
+        // 83^2 is "1" followed by two zero digits, most significant first.
+        assert_eq!(encode83(83 * 83, 3), "100");
+        assert_eq!(encode83(0, 4), "0000");
+        assert_eq!(encode83(82, 1), "~");
+    }
+
+    #[test]
+    fn srgb_linear_round_trip_is_identity() {
+        for value in [0u8, 1, 64, 128, 200, 255] {
+            let round_tripped = linear_to_srgb(srgb_to_linear(value));
+            assert_eq!(
+                round_tripped, value as i32,
+                "expected {value} to round-trip exactly, got {round_tripped}"
+            );
+        }
+    }
+
+    #[test]
+    fn encode_ac_maps_zero_to_the_midpoint_bucket() {
+        // sign_pow(0, 0.5) == 0, so quant(0) == (0 * 9.0 + 9.5).floor() == 9,
+        // the middle of the 0..=18 range, for every channel.
+        assert_eq!(encode_ac((0.0, 0.0, 0.0), 1.0), 9 * 19 * 19 + 9 * 19 + 9);
+    }
+
+    #[test]
+    fn sign_pow_preserves_sign() {
+        assert!(sign_pow(-4.0, 0.5) < 0.0);
+        assert!(sign_pow(4.0, 0.5) > 0.0);
+        assert_eq!(sign_pow(0.0, 0.5), 0.0);
+    }
+}
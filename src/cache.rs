@@ -0,0 +1,178 @@
+//! Pluggable conditional-request cache used by [`crate::PicsumClient`] when
+//! built with `.cache(true)` or `.cache_with(...)`.
+//!
+//! Entries are keyed by request URL (including query string) and store the
+//! upstream `ETag`/`Last-Modified` alongside the previously downloaded
+//! [`Image`](crate::api::Image), so a later identical request can be sent
+//! with `If-None-Match`/`If-Modified-Since` and, on a `304 Not Modified`,
+//! return the cached image instead of re-downloading it.
+
+use crate::api::Image;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::num::NonZeroUsize;
+use std::sync::Mutex;
+
+/// Default capacity of the in-memory LRU cache used by `.cache(true)`.
+pub(crate) const DEFAULT_CACHE_CAPACITY: usize = 128;
+
+/// A cached image payload plus the upstream validators needed to issue a
+/// conditional revalidation request for it.
+#[derive(Debug, Clone)]
+pub struct CachedImage {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub image: Image,
+}
+
+/// Backend for [`PicsumClient`](crate::PicsumClient)'s conditional-request
+/// cache.
+///
+/// Implement this to plug in something other than the default in-memory
+/// LRU, e.g. a cache shared across workers. Register it with
+/// [`PicsumClientBuilder::cache_with`](crate::PicsumClientBuilder::cache_with).
+pub trait ImageCache: Debug + Send + Sync {
+    /// Look up a previously cached image by `key`.
+    fn get(&self, key: &str) -> Option<CachedImage>;
+
+    /// Store `value` under `key`, evicting older entries as needed.
+    fn put(&self, key: String, value: CachedImage);
+}
+
+/// Default [`ImageCache`] backed by an in-memory least-recently-used
+/// eviction policy, used when a client is built with `.cache(true)`.
+#[derive(Debug)]
+pub(crate) struct LruImageCache {
+    capacity: usize,
+    entries: Mutex<LinkedLru>,
+}
+
+impl LruImageCache {
+    pub(crate) fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity: capacity.get(),
+            entries: Mutex::new(LinkedLru::default()),
+        }
+    }
+}
+
+impl ImageCache for LruImageCache {
+    fn get(&self, key: &str) -> Option<CachedImage> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .get(key)
+    }
+
+    fn put(&self, key: String, value: CachedImage) {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .put(key, value, self.capacity);
+    }
+}
+
+/// A minimal LRU map: a lookup table plus a use-order list of keys, with the
+/// most recently touched key at the back.
+#[derive(Debug, Default)]
+struct LinkedLru {
+    entries: HashMap<String, CachedImage>,
+    use_order: Vec<String>,
+}
+
+impl LinkedLru {
+    fn get(&mut self, key: &str) -> Option<CachedImage> {
+        let value = self.entries.get(key).cloned()?;
+        self.touch(key);
+        Some(value)
+    }
+
+    fn put(&mut self, key: String, value: CachedImage, capacity: usize) {
+        if self.entries.insert(key.clone(), value).is_none() && self.entries.len() > capacity {
+            if let Some(oldest) = (!self.use_order.is_empty()).then(|| self.use_order.remove(0)) {
+                self.entries.remove(&oldest);
+            }
+        }
+        self.touch(&key);
+    }
+
+    fn touch(&mut self, key: &str) {
+        self.use_order.retain(|used| used != key);
+        self.use_order.push(key.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached_image(id: &str) -> CachedImage {
+        CachedImage {
+            etag: Some(format!("\"{id}\"")),
+            last_modified: None,
+            image: Image {
+                id: id.to_string(),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn cache(capacity: usize) -> LruImageCache {
+        LruImageCache::new(NonZeroUsize::new(capacity).unwrap())
+    }
+
+    #[test]
+    fn returns_none_for_a_missing_key() {
+        let cache = cache(2);
+        assert!(cache.get("missing").is_none());
+    }
+
+    #[test]
+    fn put_then_get_round_trips_the_entry() {
+        let cache = cache(2);
+        cache.put("a".to_string(), cached_image("a"));
+
+        let entry = cache.get("a").expect("entry should be present");
+        assert_eq!(entry.image.id, "a");
+        assert_eq!(entry.etag.as_deref(), Some("\"a\""));
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_entry_over_capacity() {
+        let cache = cache(2);
+        cache.put("a".to_string(), cached_image("a"));
+        cache.put("b".to_string(), cached_image("b"));
+        cache.put("c".to_string(), cached_image("c"));
+
+        assert!(cache.get("a").is_none(), "a should have been evicted");
+        assert!(cache.get("b").is_some());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn getting_an_entry_protects_it_from_eviction() {
+        let cache = cache(2);
+        cache.put("a".to_string(), cached_image("a"));
+        cache.put("b".to_string(), cached_image("b"));
+
+        // Touch `a` so `b` becomes the least recently used entry instead.
+        assert!(cache.get("a").is_some());
+        cache.put("c".to_string(), cached_image("c"));
+
+        assert!(cache.get("b").is_none(), "b should have been evicted");
+        assert!(cache.get("a").is_some());
+        assert!(cache.get("c").is_some());
+    }
+
+    #[test]
+    fn overwriting_a_key_does_not_count_against_capacity() {
+        let cache = cache(2);
+        cache.put("a".to_string(), cached_image("a"));
+        cache.put("b".to_string(), cached_image("b"));
+        cache.put("a".to_string(), cached_image("a-updated"));
+
+        assert!(cache.get("b").is_some());
+        let entry = cache.get("a").expect("a should still be present");
+        assert_eq!(entry.image.id, "a-updated");
+    }
+}
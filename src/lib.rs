@@ -1,18 +1,58 @@
 pub mod api;
+mod blurhash;
+pub mod cache;
 
+use cache::{ImageCache, LruImageCache, DEFAULT_CACHE_CAPACITY};
+use std::num::NonZeroUsize;
 use std::sync::Arc;
+use std::time::Duration;
 
 static BASE_URL: &str = "https://picsum.photos";
 
+/// Default delay used as the base for exponential backoff when a client is
+/// built without an explicit `base_delay`.
+static DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// Default upper bound on the computed backoff delay between retries, when a
+/// client is built without an explicit `max_delay`.
+static DEFAULT_MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Retry behavior for requests that fail with a connection error, a `5xx`
+/// status, or a `429 Too Many Requests`.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts after the initial request. `0` disables
+    /// retries.
+    pub max_attempts: u32,
+    /// Base delay used to compute the exponential backoff between retries:
+    /// `base_delay * 2^(attempt - 1)`, plus jitter.
+    pub base_delay: Duration,
+    /// Upper bound on the computed backoff delay, regardless of `base_delay`
+    /// or attempt count. Jitter is added after this cap is applied.
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 0,
+            base_delay: DEFAULT_RETRY_BASE_DELAY,
+            max_delay: DEFAULT_MAX_RETRY_DELAY,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct PicsumClient {
     inner: Arc<PicsumClientInner>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct PicsumClientInner {
     client: reqwest::Client,
     base_url: String,
+    retry_policy: RetryPolicy,
+    cache: Option<Arc<dyn ImageCache>>,
 }
 
 impl Default for PicsumClientInner {
@@ -20,6 +60,8 @@ impl Default for PicsumClientInner {
         Self {
             client: reqwest::Client::default(),
             base_url: BASE_URL.to_string(),
+            retry_policy: RetryPolicy::default(),
+            cache: None,
         }
     }
 }
@@ -34,6 +76,9 @@ impl PicsumClient {
 pub struct PicsumClientBuilder {
     client: Option<reqwest::Client>,
     base_url: String,
+    retry_policy: RetryPolicy,
+    cache: bool,
+    custom_cache: Option<Arc<dyn ImageCache>>,
 }
 
 impl Default for PicsumClientBuilder {
@@ -41,6 +86,9 @@ impl Default for PicsumClientBuilder {
         Self {
             client: Some(reqwest::Client::default()),
             base_url: BASE_URL.to_string(),
+            retry_policy: RetryPolicy::default(),
+            cache: false,
+            custom_cache: None,
         }
     }
 }
@@ -50,6 +98,9 @@ impl PicsumClientBuilder {
         Self {
             client: None,
             base_url: BASE_URL.to_string(),
+            retry_policy: RetryPolicy::default(),
+            cache: false,
+            custom_cache: None,
         }
     }
 
@@ -63,10 +114,76 @@ impl PicsumClientBuilder {
         self
     }
 
+    /// Set the maximum number of retries attempted for a request that fails
+    /// with a connection error, `429 Too Many Requests`, or a `5xx` status.
+    ///
+    /// Defaults to `0`, which preserves the previous behavior of failing
+    /// immediately on the first error.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.retry_policy.max_attempts = max_retries;
+        self
+    }
+
+    /// Set the base delay used to compute the exponential backoff between
+    /// retries: `base_delay * 2^(attempt - 1)`, capped at `max_delay`, plus
+    /// jitter. Ignored when `max_retries` is `0`.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.retry_policy.base_delay = base_delay;
+        self
+    }
+
+    /// Set the upper bound on the computed backoff delay between retries,
+    /// regardless of `base_delay` or attempt count. Jitter is added after
+    /// this cap is applied.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.retry_policy.max_delay = max_delay;
+        self
+    }
+
+    /// Replace the client's whole [`RetryPolicy`] at once, instead of
+    /// configuring `max_retries`/`base_delay`/`max_delay` individually.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Enable the default in-memory LRU conditional-request cache, keyed by
+    /// request URL.
+    ///
+    /// When enabled, image requests are revalidated with
+    /// `If-None-Match`/`If-Modified-Since` on a cache hit, and a `304 Not
+    /// Modified` response returns the previously downloaded image without
+    /// re-reading the body. Defaults to `false`. Overridden by
+    /// [`PicsumClientBuilder::cache_with`], if also set.
+    pub fn cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Use `cache` as the conditional-request cache backend instead of the
+    /// default in-memory LRU, e.g. to share a cache across client instances
+    /// or back it with something other than process memory.
+    ///
+    /// Implies `.cache(true)`.
+    pub fn cache_with(mut self, cache: impl ImageCache + 'static) -> Self {
+        self.custom_cache = Some(Arc::new(cache));
+        self
+    }
+
     pub fn build(&self) -> PicsumClient {
+        let cache = self.custom_cache.clone().or_else(|| {
+            self.cache.then(|| {
+                let capacity = NonZeroUsize::new(DEFAULT_CACHE_CAPACITY)
+                    .expect("DEFAULT_CACHE_CAPACITY is nonzero");
+                Arc::new(LruImageCache::new(capacity)) as Arc<dyn ImageCache>
+            })
+        });
+
         let inner = PicsumClientInner {
             client: self.client.clone().unwrap_or_default(),
             base_url: self.base_url.clone(),
+            retry_policy: self.retry_policy,
+            cache,
         };
 
         PicsumClient {